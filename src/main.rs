@@ -2,6 +2,58 @@ use std::{error::Error, fs, path::PathBuf, process::exit};
 
 use clap::{Parser, ValueEnum};
 use object::{Object, ObjectSection};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+/// The kind of a `MemoryRegion`, used to drive `FlashSize` scaling instead of
+/// the previous `name.ends_with("ROM")` heuristic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "PascalCase")]
+#[value(rename_all = "lowercase")]
+pub enum MemoryRegionKind {
+    /// Flash-mapped, read-only data
+    Drom,
+    /// Flash-mapped, executable code
+    Irom,
+    /// Data RAM
+    Dram,
+    /// Instruction RAM
+    Iram,
+    /// RTC/low-power domain memory
+    Rtc,
+    /// Generic flash-mapped region not further split into DROM/IROM
+    Flash,
+}
+
+impl MemoryRegionKind {
+    /// Whether `FlashSize` scaling applies to regions of this kind.
+    fn is_flash_backed(self) -> bool {
+        matches!(
+            self,
+            MemoryRegionKind::Drom | MemoryRegionKind::Irom | MemoryRegionKind::Flash
+        )
+    }
+}
+
+impl std::fmt::Display for MemoryRegionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            MemoryRegionKind::Drom => "Drom",
+            MemoryRegionKind::Irom => "Irom",
+            MemoryRegionKind::Dram => "Dram",
+            MemoryRegionKind::Iram => "Iram",
+            MemoryRegionKind::Rtc => "Rtc",
+            MemoryRegionKind::Flash => "Flash",
+        };
+        f.pad(label)
+    }
+}
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
 #[value(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -61,6 +113,27 @@ struct Args {
 
     #[arg(short = 'w', long, default_value = "120")]
     width: usize,
+
+    /// Path to a TOML file of additional/overriding chip memory maps
+    #[arg(long, value_name = "PATH")]
+    chip_db: Option<PathBuf>,
+
+    /// Only print the per-region usage summary, skipping the per-section view
+    #[arg(long)]
+    summary_only: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+
+    /// Validate that every section is fully contained in a memory region,
+    /// printing a diagnostic and exiting with status 1 if not
+    #[arg(long)]
+    check: bool,
+
+    /// Only show sections whose region is of this kind
+    #[arg(long, value_enum)]
+    only: Option<MemoryRegionKind>,
 }
 
 fn normalize(chip_name: &str) -> String {
@@ -80,15 +153,26 @@ fn main() -> Result<(), Box<dyn Error>> {
         .collect();
     sections.sort_by(|a, b| a.address().partial_cmp(&b.address()).unwrap());
 
+    let user_chips = match &args.chip_db {
+        Some(path) => load_chip_db(path)?.chips,
+        None => Vec::new(),
+    };
+
     let chip = normalize(&args.chip);
-    let chip_memory = MEMORY.iter().find(|m| normalize(m.name) == chip);
+    let chip_memory = find_chip(&chip, &user_chips);
 
     let Some(chip_memory) = chip_memory else {
         println!("Unknown chip");
         exit(1);
     };
 
+    if args.check {
+        return run_check(&sections, &chip_memory.regions, args.flash_size);
+    }
+
     let mut last_region = usize::MAX;
+    let mut usage = vec![0u64; chip_memory.regions.len()];
+    let mut section_reports = Vec::new();
 
     // Calculate max section name width for the first column
     let mut section_name_max_width = 0;
@@ -99,19 +183,62 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
-    for section in sections {
-        let region = chip_memory.regions.iter().find(|region| {
-            region.start <= section.address()
-                && region.end(args.flash_size) >= (section.address() + section.size())
-        });
+    let text_output = args.format == OutputFormat::Text;
 
-        if let Some(region) = &region {
+    for section in &sections {
+        let region = chip_memory
+            .regions
+            .iter()
+            .enumerate()
+            .find(|(_, region)| {
+                region.start <= section.address()
+                    && region.end(args.flash_size) >= (section.address() + section.size())
+            });
+
+        if let Some((idx, _)) = &region {
+            usage[*idx] += section.size();
+        }
+
+        // `--only` filters what gets displayed, not what counts towards the
+        // per-region usage totals or the `--check` pass above.
+        let matches_only = match (args.only, &region) {
+            (None, _) => true,
+            (Some(kind), Some((_, region))) => region.kind == kind,
+            (Some(_), None) => false,
+        };
+
+        if !matches_only {
+            continue;
+        }
+
+        if let Some((_, region)) = &region {
             if region.id != last_region {
-                println!();
+                if text_output && !args.summary_only {
+                    println!();
+                }
                 last_region = region.id;
             }
         }
 
+        // `--summary-only` also applies to the JSON report: omit the
+        // per-section list there just like the text output does.
+        if !args.summary_only {
+            section_reports.push(SectionReport {
+                name: section.name().unwrap().to_string(),
+                address: section.address(),
+                size: section.size(),
+                region: region.map(|(_, region)| region.name.clone()),
+                region_kind: region.map(|(_, region)| region.kind),
+                region_start: region.map(|(_, region)| region.start),
+                region_end: region.map(|(_, region)| region.end(args.flash_size)),
+                fits: region.is_some(),
+            });
+        }
+
+        if !text_output || args.summary_only {
+            continue;
+        }
+
         print!(
             "{:width$} {:8x} {:7}",
             section.name().unwrap(),
@@ -120,20 +247,180 @@ fn main() -> Result<(), Box<dyn Error>> {
             width = section_name_max_width,
         );
 
-        if let Some(region) = &region {
-            print!(" {:8} ", region.name);
+        if let Some((_, region)) = &region {
+            print!(" {:8} {:5} ", region.name, region.kind);
             print_memory(
                 region.start,
                 region.end(args.flash_size),
                 section.address(),
                 section.size(),
-                args.width - section_name_max_width - 26, // 26 = `address` + `size` + spaces + brackets + region name
+                args.width - section_name_max_width - 35, // 35 = `address` + `size` + spaces + brackets + region name + kind
             );
         }
 
         println!();
     }
 
+    let region_reports = region_usage_reports(&chip_memory.regions, &usage, args.flash_size);
+
+    if text_output {
+        print_region_summary(&region_reports);
+    } else {
+        let report = Report {
+            sections: section_reports,
+            regions: region_reports,
+        };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct SectionReport {
+    name: String,
+    address: u64,
+    size: u64,
+    region: Option<String>,
+    region_kind: Option<MemoryRegionKind>,
+    region_start: Option<u64>,
+    region_end: Option<u64>,
+    fits: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct RegionReport {
+    name: String,
+    kind: MemoryRegionKind,
+    used: u64,
+    capacity: u64,
+    percent: f64,
+    free: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    sections: Vec<SectionReport>,
+    regions: Vec<RegionReport>,
+}
+
+/// Builds a per-`MemoryRegion` usage report (used/capacity/percent/free
+/// bytes) from the accumulated `usage` totals.
+fn region_usage_reports(
+    regions: &[MemoryRegionData],
+    usage: &[u64],
+    flash_size: Option<FlashSize>,
+) -> Vec<RegionReport> {
+    regions
+        .iter()
+        .zip(usage)
+        .map(|(region, &used)| {
+            let capacity = region.end(flash_size) - region.start;
+            let percent = if capacity == 0 {
+                0.0
+            } else {
+                (used as f64 / capacity as f64) * 100.0
+            };
+
+            RegionReport {
+                name: region.name.clone(),
+                kind: region.kind,
+                used,
+                capacity,
+                percent,
+                free: capacity.saturating_sub(used),
+            }
+        })
+        .collect()
+}
+
+/// Prints a linker-map-style summary of used/free bytes per `MemoryRegion`,
+/// plus a grand total for flash-backed regions and a combined RAM figure.
+fn print_region_summary(regions: &[RegionReport]) {
+    println!();
+    println!("Region usage:");
+
+    let mut flash_used = 0u64;
+    let mut flash_capacity = 0u64;
+    let mut ram_used = 0u64;
+    let mut ram_capacity = 0u64;
+
+    for region in regions {
+        println!(
+            "  {:10} {:8} / {:8} bytes ({:5.1}%), {:8} bytes free",
+            region.name, region.used, region.capacity, region.percent, region.free
+        );
+
+        if region.kind.is_flash_backed() {
+            flash_used += region.used;
+            flash_capacity += region.capacity;
+        } else {
+            ram_used += region.used;
+            ram_capacity += region.capacity;
+        }
+    }
+
+    println!();
+    println!("  {:10} {:8} / {:8} bytes", "Flash", flash_used, flash_capacity);
+    println!("  {:10} {:8} / {:8} bytes", "RAM", ram_used, ram_capacity);
+}
+
+/// Checks that every section is either fully contained in a `MemoryRegion`,
+/// or reports it as crossing a region boundary or entirely unmapped.
+/// Exits with status 1 if any section has a problem.
+fn run_check(
+    sections: &[object::Section],
+    regions: &[MemoryRegionData],
+    flash_size: Option<FlashSize>,
+) -> Result<(), Box<dyn Error>> {
+    let mut problems = 0;
+
+    for section in sections {
+        let name = section.name().unwrap();
+        let start = section.address();
+        let end = start + section.size();
+
+        let contained = regions
+            .iter()
+            .any(|region| region.start <= start && region.end(flash_size) >= end);
+
+        if contained {
+            continue;
+        }
+
+        // Overlap test, not just "starts inside a region": a section that
+        // starts *before* a region's start and runs into it fails the
+        // containment check above and would fail a "starts inside" test too,
+        // and so get misreported as unmapped instead of crossing.
+        let crossing = regions
+            .iter()
+            .find(|region| region.start < end && start < region.end(flash_size));
+
+        match crossing {
+            Some(region) if start < region.start => eprintln!(
+                "error: section `{name}` at {start:#x}..{end:#x} starts before region `{}` ({:#x}..{:#x}) and crosses into it",
+                region.name,
+                region.start,
+                region.end(flash_size),
+            ),
+            Some(region) => eprintln!(
+                "error: section `{name}` at {start:#x}..{end:#x} overruns region `{}` ({:#x}..{:#x})",
+                region.name,
+                region.start,
+                region.end(flash_size),
+            ),
+            None => eprintln!("error: section `{name}` at {start:#x}..{end:#x} is not mapped to any region"),
+        }
+
+        problems += 1;
+    }
+
+    if problems > 0 {
+        eprintln!("{problems} section(s) failed the memory map check");
+        exit(1);
+    }
+
+    println!("All sections fit within their regions");
     Ok(())
 }
 
@@ -170,6 +457,94 @@ fn print_memory(
     print!("]");
 }
 
+/// Loads a user-supplied chip database from a TOML file.
+///
+/// The file is a list of `[[chips]]` tables shaped like `MemoryData`/
+/// `MemoryRegionData`, e.g.:
+///
+/// ```toml
+/// [[chips]]
+/// name = "ESP32-custom"
+/// [[chips.regions]]
+/// id = 0
+/// name = "DRAM"
+/// kind = "Dram"
+/// start = 0x3FFB0000
+/// length = 180224
+/// ```
+fn load_chip_db(path: &std::path::Path) -> Result<ChipDb, Box<dyn Error>> {
+    let text = fs::read_to_string(path)?;
+    Ok(toml::from_str(&text)?)
+}
+
+/// Looks up a chip by its normalized name, preferring `user_chips` over the
+/// built-in `MEMORY` table so a `--chip-db` file can override a shipped part.
+fn find_chip(normalized_chip: &str, user_chips: &[MemoryData]) -> Option<MemoryData> {
+    user_chips
+        .iter()
+        .find(|m| normalize(&m.name) == normalized_chip)
+        .cloned()
+        .or_else(|| {
+            MEMORY
+                .iter()
+                .find(|m| normalize(m.name) == normalized_chip)
+                .map(MemoryData::from)
+        })
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MemoryRegionData {
+    pub id: usize,
+    pub name: String,
+    pub kind: MemoryRegionKind,
+    pub start: u64,
+    pub length: u64,
+}
+
+impl MemoryRegionData {
+    pub fn end(&self, flash_size: Option<FlashSize>) -> u64 {
+        let length = match self.kind.is_flash_backed() && flash_size.is_some() {
+            true => flash_size.unwrap().bytes(),
+            false => self.length,
+        };
+
+        self.start + length
+    }
+}
+
+impl From<&MemoryRegion> for MemoryRegionData {
+    fn from(region: &MemoryRegion) -> Self {
+        MemoryRegionData {
+            id: region.id,
+            name: region.name.to_string(),
+            kind: region.kind,
+            start: region.start,
+            length: region.length,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MemoryData {
+    pub name: String,
+    pub regions: Vec<MemoryRegionData>,
+}
+
+impl From<&Memory> for MemoryData {
+    fn from(memory: &Memory) -> Self {
+        MemoryData {
+            name: memory.name.to_string(),
+            regions: memory.regions.iter().map(MemoryRegionData::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ChipDb {
+    #[serde(default)]
+    pub chips: Vec<MemoryData>,
+}
+
 pub struct Memory {
     name: &'static str,
     regions: &'static [MemoryRegion],
@@ -178,13 +553,14 @@ pub struct Memory {
 pub struct MemoryRegion {
     id: usize,
     name: &'static str,
+    kind: MemoryRegionKind,
     start: u64,
     length: u64,
 }
 
 impl MemoryRegion {
     pub fn end(&self, flash_size: Option<FlashSize>) -> u64 {
-        let length = match self.name.ends_with("ROM") && flash_size.is_some() {
+        let length = match self.kind.is_flash_backed() && flash_size.is_some() {
             true => flash_size.unwrap().bytes(),
             false => self.length,
         };
@@ -201,24 +577,28 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DRAM",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FFB0000,
                 length: 176 * 1024,
             },
             MemoryRegion {
                 id: 1,
                 name: "IRAM",
+                kind: MemoryRegionKind::Iram,
                 start: 0x40080000,
                 length: 128 * 1024,
             },
             MemoryRegion {
                 id: 2,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3F400000,
                 length: 4 * 1024 * 1024,
             },
             MemoryRegion {
                 id: 3,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x400D0000,
                 length: 4 * 1024 * 1024,
             },
@@ -230,24 +610,28 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DRAM",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FFB0000,
                 length: 0x40000000 - 0x3FFB0000,
             },
             MemoryRegion {
                 id: 1,
                 name: "IRAM",
+                kind: MemoryRegionKind::Iram,
                 start: 0x40020000,
                 length: 0x40070000 - 0x40020000,
             },
             MemoryRegion {
                 id: 2,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3F000000,
                 length: 0x3FF80000 - 0x3F000000,
             },
             MemoryRegion {
                 id: 3,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x40080000,
                 length: 0x40800000 - 0x40080000,
             },
@@ -259,24 +643,28 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DRAM",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FC8_8000,
                 length: 0x3FCE_FFFF - 0x3FC8_8000,
             },
             MemoryRegion {
                 id: 1,
                 name: "IRAM",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4037_8000,
                 length: 0x403D_FFFF - 0x4037_8000,
             },
             MemoryRegion {
                 id: 2,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3C00_0000,
                 length: 0x3DFF_FFFF - 0x3C00_0000,
             },
             MemoryRegion {
                 id: 3,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x4200_0000,
                 length: 0x43FF_FFFF - 0x4200_0000,
             },
@@ -288,24 +676,28 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DRAM",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FCA0000,
                 length: 0x3FCE0000 - 0x3FCA0000,
             },
             MemoryRegion {
                 id: 1,
                 name: "IRAM",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4037C000,
                 length: 0x403C0000 - 0x4037C000,
             },
             MemoryRegion {
                 id: 2,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3C000000,
                 length: 0x3C400000 - 0x3C000000,
             },
             MemoryRegion {
                 id: 3,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x42000000,
                 length: 0x42400000 - 0x42000000,
             },
@@ -317,24 +709,28 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DRAM",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FC80000,
                 length: 0x50000,
             },
             MemoryRegion {
                 id: 1,
                 name: "IRAM",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4037C000,
                 length: 400 * 1024,
             },
             MemoryRegion {
                 id: 2,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3C000000,
                 length: 0x400000,
             },
             MemoryRegion {
                 id: 3,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x42000000,
                 length: 0x400000,
             },
@@ -346,12 +742,14 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "RAM",
+                kind: MemoryRegionKind::Dram,
                 start: 0x40800000,
                 length: 0x40880000 - 0x40800000,
             },
             MemoryRegion {
                 id: 1,
                 name: "ROM",
+                kind: MemoryRegionKind::Flash,
                 start: 0x42000000,
                 length: 0x10000 << 8,
             },
@@ -363,12 +761,14 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DRAM",
+                kind: MemoryRegionKind::Dram,
                 start: 0x40800000,
                 length: 0x40850000 - 0x40800000,
             },
             MemoryRegion {
                 id: 1,
                 name: "ROM",
+                kind: MemoryRegionKind::Flash,
                 start: 0x42000000,
                 length: 0x10000 << 8,
             },
@@ -384,66 +784,77 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3F400000,
                 length: 4 * 1024 * 1024,
             },
             MemoryRegion {
                 id: 1,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x400D0000,
                 length: 4 * 1024 * 1024,
             },
             MemoryRegion {
                 id: 2,
                 name: "DRTC_FAST",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x3FF8_0000,
                 length: 0x3FF8_1FFF - 0x3FF8_0000,
             },
             MemoryRegion {
                 id: 3,
                 name: "DRAM2",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FFA_E000,
                 length: 0x3FFD_FFFF - 0x3FFA_E000,
             },
             MemoryRegion {
                 id: 4,
                 name: "DRAM1",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FFE_0000,
                 length: 0x3FFF_FFFF - 0x3FFE_0000,
             },
             MemoryRegion {
                 id: 5,
                 name: "Cache",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4007_0000,
                 length: 0x4007_FFFF - 0x4007_0000,
             },
             MemoryRegion {
                 id: 6,
                 name: "IRAM0",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4008_0000,
                 length: 0x4009_FFFF - 0x4008_0000,
             },
             MemoryRegion {
                 id: 7,
                 name: "IRAM1",
+                kind: MemoryRegionKind::Iram,
                 start: 0x400A_0000,
                 length: 0x400A_FFFF - 0x400A_0000,
             },
             MemoryRegion {
                 id: 8,
                 name: "IRAM1*",
+                kind: MemoryRegionKind::Iram,
                 start: 0x400B_8000,
                 length: 0x400B_FFFF - 0x400B_8000,
             },
             MemoryRegion {
                 id: 9,
                 name: "IRTC_FAST",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x400C_0000,
                 length: 0x400C_1FFF - 0x400C_0000,
             },
             MemoryRegion {
                 id: 10,
                 name: "RTC_SLOW",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x5000_0000,
                 length: 0x5000_1FFF - 0x5000_0000,
             },
@@ -455,54 +866,63 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3F000000,
                 length: 0x3FF80000 - 0x3F000000,
             },
             MemoryRegion {
                 id: 1,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x40080000,
                 length: 0x40800000 - 0x40080000,
             },
             MemoryRegion {
                 id: 2,
                 name: "DRTC_FAST",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x3FF9_E000,
                 length: 0x3FF9_FFFF - 0x3FF9_E000,
             },
             MemoryRegion {
                 id: 3,
                 name: "DRAM0",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FFB_0000,
                 length: 0x3FFB_7FFF - 0x3FFB_0000,
             },
             MemoryRegion {
                 id: 4,
                 name: "DRAM1",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FFB_8000,
                 length: 0x3FFF_FFFF - 0x3FFB_8000,
             },
             MemoryRegion {
                 id: 5,
                 name: "IRAM0",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4002_0000,
                 length: 0x4002_7FFF - 0x4002_0000,
             },
             MemoryRegion {
                 id: 6,
                 name: "IRAM1",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4002_8000,
                 length: 0x4006_FFFF - 0x4002_8000,
             },
             MemoryRegion {
                 id: 7,
                 name: "IRTC_FAST",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x4007_0000,
                 length: 0x4007_1FFF - 0x4007_0000,
             },
             MemoryRegion {
                 id: 8,
                 name: "RTC_SLOW",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x5000_0000,
                 length: 0x5000_1FFF - 0x5000_0000,
             },
@@ -514,48 +934,56 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3C00_0000,
                 length: 0x3DFF_FFFF - 0x3C00_0000,
             },
             MemoryRegion {
                 id: 1,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x4200_0000,
                 length: 0x43FF_FFFF - 0x4200_0000,
             },
             MemoryRegion {
                 id: 2,
                 name: "DRAM1",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FC8_8000,
                 length: 0x3FCE_FFFF - 0x3FC8_8000,
             },
             MemoryRegion {
                 id: 3,
                 name: "DRAM2",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FCF_0000,
                 length: 0x3FCF_FFFF - 0x3FCF_0000,
             },
             MemoryRegion {
                 id: 4,
                 name: "IRAM1",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4037_0000,
                 length: 0x4037_7FFF - 0x4037_0000,
             },
             MemoryRegion {
                 id: 5,
                 name: "IRAM2",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4037_8000,
                 length: 0x403D_FFFF - 0x4037_8000,
             },
             MemoryRegion {
                 id: 6,
                 name: "RTC_SLOW",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x5000_0000,
                 length: 0x5000_1FFF - 0x5000_0000,
             },
             MemoryRegion {
                 id: 7,
                 name: "RTC_FAST",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x600F_E000,
                 length: 0x600F_FFFF - 0x600F_E000,
             },
@@ -567,30 +995,35 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3C000000,
                 length: 0x3C400000 - 0x3C000000,
             },
             MemoryRegion {
                 id: 1,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x42000000,
                 length: 0x42400000 - 0x42000000,
             },
             MemoryRegion {
                 id: 2,
                 name: "DRAM1",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FCA_0000,
                 length: 0x3FCD_FFFF - 0x3FCA_0000,
             },
             MemoryRegion {
                 id: 3,
                 name: "IRAM0",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4037_C000,
                 length: 0x4037_FFFF - 0x4037_C000,
             },
             MemoryRegion {
                 id: 4,
                 name: "IRAM1",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4038_0000,
                 length: 0x403B_FFFF - 0x4038_0000,
             },
@@ -602,36 +1035,42 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DROM",
+                kind: MemoryRegionKind::Drom,
                 start: 0x3C000000,
                 length: 0x400000,
             },
             MemoryRegion {
                 id: 1,
                 name: "IROM",
+                kind: MemoryRegionKind::Irom,
                 start: 0x42000000,
                 length: 0x400000,
             },
             MemoryRegion {
                 id: 2,
                 name: "DRAM1",
+                kind: MemoryRegionKind::Dram,
                 start: 0x3FC8_0000,
                 length: 0x3FCD_FFFF - 0x3FC8_0000,
             },
             MemoryRegion {
                 id: 3,
                 name: "IRAM0",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4037_C000,
                 length: 0x4037_FFFF - 0x4037_C000,
             },
             MemoryRegion {
                 id: 4,
                 name: "IRAM1",
+                kind: MemoryRegionKind::Iram,
                 start: 0x4038_0000,
                 length: 0x403D_FFFF - 0x4038_0000,
             },
             MemoryRegion {
                 id: 4,
                 name: "RTCFAST",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x5000_0000,
                 length: 0x5000_1FFF - 0x5000_0000,
             },
@@ -643,18 +1082,21 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "RAM",
+                kind: MemoryRegionKind::Dram,
                 start: 0x40800000,
                 length: 0x40880000 - 0x40800000,
             },
             MemoryRegion {
                 id: 1,
                 name: "ROM",
+                kind: MemoryRegionKind::Flash,
                 start: 0x42000000,
                 length: 0x10000 << 8,
             },
             MemoryRegion {
                 id: 2,
                 name: "LPRAM",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x5000_0000,
                 length: 0x5000_3FFF - 0x5000_0000,
             },
@@ -666,18 +1108,21 @@ const MEMORY: &[Memory] = &[
             MemoryRegion {
                 id: 0,
                 name: "DRAM",
+                kind: MemoryRegionKind::Dram,
                 start: 0x40800000,
                 length: 0x40850000 - 0x40800000,
             },
             MemoryRegion {
                 id: 1,
                 name: "ROM",
+                kind: MemoryRegionKind::Flash,
                 start: 0x42000000,
                 length: 0x10000 << 8,
             },
             MemoryRegion {
                 id: 2,
                 name: "LPRAM",
+                kind: MemoryRegionKind::Rtc,
                 start: 0x5000_0000,
                 length: 0x5000_0FFF - 0x5000_0000,
             },